@@ -2,6 +2,8 @@
 extern crate test;
 
 use load::zipf::Zipf;
+use rand::SeedableRng;
+use rand_pcg::Pcg64;
 use test::Bencher;
 
 #[bench]
@@ -59,3 +61,26 @@ fn bench_zipf_batch_64(b: &mut Bencher) {
     let mut output = vec![0.0; 64];
     b.iter(|| zipf.sample_batch(&u_values, &mut output));
 }
+
+/// O(1) jump-ahead on a `Pcg64`-backed iterator vs naively draining the same
+/// number of elements on a `StdRng`-backed one — see `SampleIterator::nth`.
+#[bench]
+fn bench_zipf_nth_million_jump_ahead(b: &mut Bencher) {
+    let zipf = Zipf::new(1.0..1000000.0, 1.07).unwrap();
+    b.iter(|| {
+        let mut iter = zipf.iter().with_rng(Pcg64::seed_from_u64(42));
+        iter.nth(1_000_000)
+    });
+}
+
+#[bench]
+fn bench_zipf_nth_million_naive_drain(b: &mut Bencher) {
+    let zipf = Zipf::new(1.0..1000000.0, 1.07).unwrap();
+    b.iter(|| {
+        let mut iter = zipf.iter();
+        for _ in 0..1_000_000 {
+            iter.next();
+        }
+        iter.next()
+    });
+}
@@ -0,0 +1,12 @@
+/// Common interface for seeded distributions sampled via a single uniform
+/// draw `u ∈ [0, 1)`.
+///
+/// Implementors cache their transform constants at construction so `sample`
+/// stays a handful of arithmetic ops. This is what lets [`SampleIterator`](
+/// crate::distributions::SampleIterator) stay generic over the distribution:
+/// `Zipf`, [`Pareto`](crate::distributions::Pareto) and
+/// [`Weibull`](crate::distributions::Weibull) all implement it.
+pub trait Distribution: Copy {
+    /// Converts a uniform random value `u ∈ [0, 1)` into a distributed value.
+    fn sample(&self, u: f64) -> f64;
+}
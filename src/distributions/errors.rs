@@ -0,0 +1,25 @@
+use std::fmt;
+
+/// Errors that can occur when creating or using the distributions in this module.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DistributionError {
+    /// The scale parameter must be greater than 0.
+    InvalidScale(f64),
+    /// The shape parameter must be greater than 0.
+    InvalidShape(f64),
+}
+
+impl fmt::Display for DistributionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DistributionError::InvalidScale(scale) => {
+                write!(f, "Scale parameter must be > 0, got: {}", scale)
+            }
+            DistributionError::InvalidShape(shape) => {
+                write!(f, "Shape parameter must be > 0, got: {}", shape)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DistributionError {}
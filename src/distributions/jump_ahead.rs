@@ -0,0 +1,40 @@
+use rand::Rng;
+use rand_pcg::Pcg32;
+use rand_pcg::Pcg64;
+
+/// RNGs whose state at step `i + delta` can be computed directly from the
+/// state at step `i`, letting callers skip ahead in O(1) instead of drawing
+/// and discarding `delta` values.
+///
+/// PCG generators are a linear congruential generator (LCG) under an output
+/// permutation, and an LCG's state after `delta` steps is `a^delta * state +
+/// c * (a^delta - 1) / (a - 1)` — computable by modular exponentiation in
+/// `O(log delta)` rather than `O(delta)`. Counter-based or otherwise
+/// non-linear RNGs (e.g. `StdRng`'s ChaCha core, as exposed by this crate)
+/// don't implement this trait, and [`SampleIterator::skip_ahead`](
+/// crate::distributions::SampleIterator::skip_ahead) falls back to drawing
+/// and discarding values for them.
+pub trait JumpAhead: Rng {
+    /// Advances the generator's state as if `delta` `u64` samples (e.g.
+    /// `delta` calls to [`Rng::gen::<f64>`]) had been drawn and discarded,
+    /// in O(1).
+    fn jump_ahead(&mut self, delta: u64);
+}
+
+impl JumpAhead for Pcg64 {
+    #[inline]
+    fn jump_ahead(&mut self, delta: u64) {
+        self.advance(delta as u128);
+    }
+}
+
+impl JumpAhead for Pcg32 {
+    // `Pcg32`'s underlying LCG advances one step per `next_u32` call, but
+    // `RngCore::next_u64` (what a `u64`/`f64` sample draws) is implemented as
+    // `impls::next_u64_via_u32`, which takes *two* `next_u32` steps. So
+    // advancing `delta` samples means advancing the LCG by `2 * delta` steps.
+    #[inline]
+    fn jump_ahead(&mut self, delta: u64) {
+        self.advance(delta.wrapping_mul(2));
+    }
+}
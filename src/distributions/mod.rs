@@ -0,0 +1,24 @@
+//! Shared seeded distribution samplers for load testing.
+//!
+//! Continuous, heavy-tailed distributions beyond `Zipf`: [`Pareto`] and
+//! [`Weibull`]. `Zipf`, [`Pareto`] and [`Weibull`] share the [`Distribution`]
+//! trait and the generic [`SampleIterator`], so callers get one consistent
+//! seeded-iterator API regardless of which of them they pick. The discrete
+//! samplers `ZipfDiscrete` and `ZipfAlias` don't implement [`Distribution`]
+//! and come with their own iterator types.
+
+mod distribution;
+mod errors;
+mod jump_ahead;
+mod pareto;
+mod sample_iterator;
+mod weibull;
+
+pub use distribution::Distribution;
+pub use errors::DistributionError;
+pub use jump_ahead::JumpAhead;
+pub use pareto::Pareto;
+pub use pareto::ParetoIterator;
+pub use sample_iterator::SampleIterator;
+pub use weibull::Weibull;
+pub use weibull::WeibullIterator;
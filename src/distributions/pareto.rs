@@ -0,0 +1,115 @@
+use rand::rngs::StdRng;
+
+use crate::distributions::Distribution;
+use crate::distributions::DistributionError;
+use crate::distributions::SampleIterator;
+
+/// Pareto (power-law) distribution, sampled via its closed-form inverse CDF.
+///
+/// The Pareto struct caches the inverse shape exponent at construction for
+/// efficient generation of Pareto-distributed values.
+#[derive(Debug, Clone, Copy)]
+pub struct Pareto {
+    /// Scale parameter: the minimum possible value, `x_m > 0`.
+    x_m: f64,
+    /// Shape parameter: the tail heaviness, `alpha > 0`.
+    #[allow(dead_code)]
+    alpha: f64,
+    /// Cached `1 / alpha`.
+    inv_alpha: f64,
+}
+
+impl Pareto {
+    /// Creates a Pareto distribution with scale `x_m > 0` and shape `alpha > 0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use load::distributions::Pareto;
+    /// let pareto = Pareto::new(1.0, 2.0).unwrap();
+    /// let value = pareto.sample(0.5);
+    /// assert_eq!(format!("{:.4}", value), "1.4142");
+    /// ```
+    pub fn new(x_m: f64, alpha: f64) -> Result<Self, DistributionError> {
+        if x_m <= 0.0 {
+            return Err(DistributionError::InvalidScale(x_m));
+        }
+        if alpha <= 0.0 {
+            return Err(DistributionError::InvalidShape(alpha));
+        }
+
+        Ok(Self {
+            x_m,
+            alpha,
+            inv_alpha: 1.0 / alpha,
+        })
+    }
+
+    /// Converts a uniform random value `u ∈ [0, 1)` to a Pareto-distributed variate.
+    #[inline]
+    pub fn sample(&self, u: f64) -> f64 {
+        self.x_m / (1.0 - u).powf(self.inv_alpha)
+    }
+
+    /// Batch sample multiple values for better performance.
+    pub fn sample_batch(&self, u_values: &[f64], output: &mut [f64]) {
+        assert_eq!(
+            u_values.len(),
+            output.len(),
+            "Input and output slices must have the same length"
+        );
+
+        for (u, out) in u_values.iter().zip(output.iter_mut()) {
+            *out = self.sample(*u);
+        }
+    }
+
+    /// Creates an infinite iterator that yields Pareto-distributed values with a default random number generator.
+    pub fn iter(&self) -> ParetoIterator {
+        SampleIterator::new(*self)
+    }
+}
+
+impl Distribution for Pareto {
+    #[inline]
+    fn sample(&self, u: f64) -> f64 {
+        Pareto::sample(self, u)
+    }
+}
+
+/// Iterator that generates Pareto-distributed values using the default,
+/// cryptographically secure `StdRng`.
+///
+/// A type alias over the shared, distribution-generic [`SampleIterator`].
+pub type ParetoIterator = SampleIterator<Pareto, StdRng>;
+
+#[cfg(test)]
+mod tests {
+    use crate::distributions::*;
+
+    #[test]
+    fn test_pareto_rejects_invalid_params() {
+        assert!(Pareto::new(0.0, 2.0).is_err());
+        assert!(Pareto::new(-1.0, 2.0).is_err());
+        assert!(Pareto::new(1.0, 0.0).is_err());
+        assert!(Pareto::new(1.0, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_pareto_values_above_scale() {
+        let pareto = Pareto::new(2.0, 1.5).unwrap();
+        let samples: Vec<f64> = pareto.iter().take(1000).collect();
+        assert!(samples.iter().all(|&v| v >= 2.0));
+    }
+
+    #[test]
+    fn test_pareto_sample_batch_matches_sample() {
+        let pareto = Pareto::new(1.0, 1.2).unwrap();
+        let u_values = vec![0.1, 0.25, 0.5, 0.75, 0.9];
+        let mut output = vec![0.0; u_values.len()];
+        pareto.sample_batch(&u_values, &mut output);
+
+        for (u, expected) in u_values.iter().zip(output.iter()) {
+            assert_eq!(pareto.sample(*u), *expected);
+        }
+    }
+}
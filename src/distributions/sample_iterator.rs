@@ -0,0 +1,392 @@
+use std::any::Any;
+
+use rand::prelude::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+use rand_pcg::Pcg32;
+use rand_pcg::Pcg64;
+
+use crate::distributions::Distribution;
+use crate::distributions::JumpAhead;
+
+const DEFAULT_SEED: u64 = 666;
+
+/// Iterator that draws values from any [`Distribution`] `D`, using a
+/// configurable random number generator `R`.
+///
+/// This is the shared iterator behind `load::zipf::ZipfIterator`,
+/// [`ParetoIterator`](crate::distributions::ParetoIterator) and
+/// [`WeibullIterator`](crate::distributions::WeibullIterator) — one
+/// consistent seeded-iterator API across the distributions that implement
+/// [`Distribution`]. The discrete samplers `load::zipf::ZipfDiscrete` and
+/// `load::zipf::ZipfAlias` have their own iterator types instead.
+///
+/// # Examples
+/// ```
+/// use load::distributions::Pareto;
+/// use load::distributions::SampleIterator;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let pareto = Pareto::new(1.0, 2.0).unwrap();
+///
+/// // Create iterator with default seed
+/// let values: Vec<f64> = pareto.iter().take(3).collect();
+/// assert!(values.iter().all(|&v| v >= 1.0));
+///
+/// // Create iterator with custom seed (convenient)
+/// let iter = SampleIterator::with_seed(pareto, 42);
+/// let values: Vec<f64> = iter.take(3).collect();
+/// assert!(values.iter().all(|&v| v >= 1.0));
+///
+/// // Create iterator with custom RNG (fluent API). `split`/`stream` panic on
+/// // the result, since `rng`'s seed isn't known to this crate;
+/// // use `with_seeded_rng` instead if you need reproducible sub-streams.
+/// let rng = StdRng::seed_from_u64(123);
+/// let values: Vec<f64> = pareto.iter().with_rng(rng).take(3).collect();
+/// assert!(values.iter().all(|&v| v >= 1.0));
+///
+/// // Create iterator with a custom RNG *and* its seed, to still support
+/// // `split`/`stream`.
+/// let values: Vec<f64> = pareto
+///     .iter()
+///     .with_seeded_rng(StdRng::seed_from_u64(123), 123)
+///     .split(4)
+///     .remove(0)
+///     .take(3)
+///     .collect();
+/// assert!(values.iter().all(|&v| v >= 1.0));
+///
+/// // Fast-forwarding with `nth`/`skip_ahead` is O(1) for a jump-capable RNG
+/// // like `rand_pcg::Pcg64` instead of drawing and discarding every value.
+/// use rand_pcg::Pcg64;
+/// let mut iter = pareto.iter().with_rng(Pcg64::seed_from_u64(42));
+/// let millionth = iter.nth(999_999).unwrap();
+/// assert!(millionth >= 1.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SampleIterator<D: Distribution, R: Rng> {
+    distribution: D,
+    rng: R,
+    /// Seed this iterator (or its [`split`](Self::split)/[`stream`](Self::stream)
+    /// sub-streams) were derived from, if known. `None` after [`with_rng`](
+    /// Self::with_rng), since an arbitrary caller-provided `R` doesn't expose
+    /// the seed it was constructed with; `split`/`stream` panic in that case.
+    master_seed: Option<u64>,
+}
+
+impl<D: Distribution> SampleIterator<D, StdRng> {
+    /// Creates a new `SampleIterator` with a default-seeded `StdRng`.
+    pub fn new(distribution: D) -> Self {
+        Self::with_seed(distribution, DEFAULT_SEED)
+    }
+
+    /// Creates a new `SampleIterator` with the specified seed.
+    /// This is a convenience method that creates an StdRng internally.
+    pub fn with_seed(distribution: D, seed: u64) -> Self {
+        Self {
+            distribution,
+            rng: StdRng::seed_from_u64(seed),
+            master_seed: Some(seed),
+        }
+    }
+}
+
+impl<D: Distribution, R: Rng> SampleIterator<D, R> {
+    /// Creates a new `SampleIterator` with the provided random number
+    /// generator, which may be of any type implementing `R: Rng`.
+    ///
+    /// The resulting iterator doesn't support [`split`](Self::split)/
+    /// [`stream`](Self::stream): this crate has no way to know what seed (if
+    /// any) `rng` was constructed with, so it can't derive reproducible
+    /// sub-streams from it. Use [`with_seeded_rng`](Self::with_seeded_rng)
+    /// if you need both a custom `R` and reproducible sub-streams.
+    pub fn with_rng<R2: Rng>(self, rng: R2) -> SampleIterator<D, R2> {
+        SampleIterator {
+            distribution: self.distribution,
+            rng,
+            master_seed: None,
+        }
+    }
+
+    /// Creates a new `SampleIterator` with the provided random number
+    /// generator and the seed it was constructed with, so that
+    /// [`split`](Self::split)/[`stream`](Self::stream) can still derive
+    /// reproducible sub-streams from it.
+    ///
+    /// `seed` is trusted as-is and not verified against `rng`'s actual state.
+    pub fn with_seeded_rng<R2: Rng>(self, rng: R2, seed: u64) -> SampleIterator<D, R2> {
+        SampleIterator {
+            distribution: self.distribution,
+            rng,
+            master_seed: Some(seed),
+        }
+    }
+}
+
+impl<D: Distribution, R: Rng + SeedableRng> SampleIterator<D, R> {
+    /// Derives `worker_count` independent, reproducible sub-streams from this
+    /// iterator's master seed.
+    ///
+    /// Each sub-stream seeds its RNG from a hash of `(master_seed, worker_id)`,
+    /// so the whole fleet is reproducible from the single master seed while
+    /// every worker draws from a statistically independent sequence. The
+    /// union of all workers still follows the target distribution.
+    ///
+    /// # Panics
+    /// Panics if this iterator has no known master seed, i.e. it was built
+    /// via [`with_rng`](Self::with_rng) rather than [`new`](Self::new),
+    /// [`with_seed`](Self::with_seed) or [`with_seeded_rng`](Self::with_seeded_rng).
+    pub fn split(&self, worker_count: usize) -> Vec<Self> {
+        (0..worker_count)
+            .map(|worker_id| self.stream(worker_id, worker_count))
+            .collect()
+    }
+
+    /// Derives the sub-stream for `worker_id` out of `worker_count` workers,
+    /// equivalent to `self.split(worker_count)[worker_id]` without building
+    /// the other workers' streams.
+    ///
+    /// # Panics
+    /// Panics if this iterator has no known master seed, i.e. it was built
+    /// via [`with_rng`](Self::with_rng) rather than [`new`](Self::new),
+    /// [`with_seed`](Self::with_seed) or [`with_seeded_rng`](Self::with_seeded_rng).
+    pub fn stream(&self, worker_id: usize, worker_count: usize) -> Self {
+        assert!(
+            worker_id < worker_count,
+            "worker_id {} must be < worker_count {}",
+            worker_id,
+            worker_count
+        );
+        let master_seed = self.master_seed.expect(
+            "split/stream require a known master seed; this iterator was built with with_rng, \
+             which doesn't carry one — use with_seed or with_seeded_rng instead",
+        );
+
+        let seed = mix_seed(master_seed, worker_id as u64);
+        Self {
+            distribution: self.distribution,
+            rng: R::seed_from_u64(seed),
+            master_seed: Some(seed),
+        }
+    }
+}
+
+impl<D: Distribution, R: Rng + 'static> SampleIterator<D, R> {
+    /// Advances the iterator's state by `n` steps without producing their
+    /// values, e.g. to resume a reproducible stream at the sample it left
+    /// off at, or to hand each shard of a partitioned stream its starting
+    /// offset.
+    ///
+    /// Uses the O(1) [`JumpAhead`] primitive when `R` is a PCG generator
+    /// (`rand_pcg::Pcg64` or `rand_pcg::Pcg32`); otherwise falls back to
+    /// drawing and discarding `n` uniform values, same as repeatedly calling
+    /// [`next`](Iterator::next).
+    pub fn skip_ahead(&mut self, n: u64) {
+        if let Some(rng) = (&mut self.rng as &mut dyn Any).downcast_mut::<Pcg64>() {
+            rng.jump_ahead(n);
+        } else if let Some(rng) = (&mut self.rng as &mut dyn Any).downcast_mut::<Pcg32>() {
+            rng.jump_ahead(n);
+        } else {
+            for _ in 0..n {
+                self.rng.r#gen::<f64>();
+            }
+        }
+    }
+}
+
+impl<D: Distribution, R: Rng + 'static> Iterator for SampleIterator<D, R> {
+    type Item = f64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let u = self.rng.r#gen::<f64>();
+        Some(self.distribution.sample(u))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.skip_ahead(n as u64);
+        self.next()
+    }
+}
+
+/// Mixes a master seed and a worker id into a decorrelated `u64` seed, using
+/// the splitmix64 finalizer so nearby `worker_id`s don't produce correlated
+/// RNG states.
+#[inline]
+fn mix_seed(master_seed: u64, worker_id: u64) -> u64 {
+    let mut z = master_seed.wrapping_add(worker_id.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg32;
+    use rand_pcg::Pcg64;
+
+    use crate::distributions::Pareto;
+    use crate::distributions::SampleIterator;
+
+    #[test]
+    fn test_sample_iterator_rng_consistency() {
+        let pareto = Pareto::new(1.0, 1.5).unwrap();
+
+        let iter1 = SampleIterator::with_seed(pareto, 42);
+        let iter2 = SampleIterator::with_seed(pareto, 42);
+
+        let seq1: Vec<f64> = iter1.take(10).collect();
+        let seq2: Vec<f64> = iter2.take(10).collect();
+
+        assert_eq!(seq1, seq2, "Same seed should produce identical sequences");
+    }
+
+    #[test]
+    fn test_sample_iterator_rng_reproducibility() {
+        let pareto = Pareto::new(1.0, 1.5).unwrap();
+        let mut iter = pareto.iter().with_rng(StdRng::seed_from_u64(789));
+
+        let seq1: Vec<f64> = (&mut iter).take(8).collect();
+
+        let mut iter = pareto.iter().with_rng(StdRng::seed_from_u64(789));
+        let seq2: Vec<f64> = (&mut iter).take(8).collect();
+
+        assert_eq!(seq1, seq2, "Same seed should reproduce identical sequence");
+    }
+
+    #[test]
+    fn test_sample_iterator_split_is_reproducible() {
+        let pareto = Pareto::new(1.0, 1.5).unwrap();
+
+        let workers1 = SampleIterator::with_seed(pareto, 42).split(8);
+        let workers2 = SampleIterator::with_seed(pareto, 42).split(8);
+        assert_eq!(workers1.len(), 8);
+
+        for (mut w1, mut w2) in workers1.into_iter().zip(workers2) {
+            let seq1: Vec<f64> = (&mut w1).take(10).collect();
+            let seq2: Vec<f64> = (&mut w2).take(10).collect();
+            assert_eq!(seq1, seq2, "Same master seed should reproduce each worker");
+        }
+    }
+
+    #[test]
+    fn test_nth_matches_drain_with_stdrng_fallback() {
+        let pareto = Pareto::new(1.0, 1.5).unwrap();
+
+        let mut drained = SampleIterator::with_seed(pareto, 42);
+        for _ in 0..99 {
+            drained.next();
+        }
+        let expected = drained.next();
+
+        let mut jumped = SampleIterator::with_seed(pareto, 42);
+        let actual = jumped.nth(99);
+
+        assert_eq!(actual, expected, "nth(99) must equal the 100th drawn value");
+        assert_eq!(
+            jumped.next(),
+            drained.next(),
+            "iterators must agree on what comes after nth() too"
+        );
+    }
+
+    #[test]
+    fn test_nth_matches_drain_with_jump_capable_rng() {
+        let pareto = Pareto::new(1.0, 1.5).unwrap();
+
+        let mut drained = pareto.iter().with_rng(Pcg64::seed_from_u64(7));
+        for _ in 0..99 {
+            drained.next();
+        }
+        let expected = drained.next();
+
+        let mut jumped = pareto.iter().with_rng(Pcg64::seed_from_u64(7));
+        let actual = jumped.nth(99);
+
+        assert_eq!(
+            actual, expected,
+            "the O(1) jump-ahead path must agree with the O(n) drain path"
+        );
+    }
+
+    #[test]
+    fn test_nth_matches_drain_with_pcg32() {
+        let pareto = Pareto::new(1.0, 1.5).unwrap();
+
+        let mut drained = pareto.iter().with_rng(Pcg32::seed_from_u64(7));
+        for _ in 0..99 {
+            drained.next();
+        }
+        let expected = drained.next();
+
+        let mut jumped = pareto.iter().with_rng(Pcg32::seed_from_u64(7));
+        let actual = jumped.nth(99);
+
+        assert_eq!(
+            actual, expected,
+            "Pcg32's jump_ahead must account for next_u64 taking two next_u32 steps"
+        );
+    }
+
+    #[test]
+    fn test_with_seeded_rng_split_is_reproducible() {
+        let pareto = Pareto::new(1.0, 1.5).unwrap();
+
+        let workers1 = pareto
+            .iter()
+            .with_seeded_rng(Pcg64::seed_from_u64(42), 42)
+            .split(4);
+        let workers2 = pareto
+            .iter()
+            .with_seeded_rng(Pcg64::seed_from_u64(42), 42)
+            .split(4);
+
+        for (mut w1, mut w2) in workers1.into_iter().zip(workers2) {
+            let seq1: Vec<f64> = (&mut w1).take(10).collect();
+            let seq2: Vec<f64> = (&mut w2).take(10).collect();
+            assert_eq!(seq1, seq2, "Same seed should reproduce each worker");
+        }
+    }
+
+    #[test]
+    fn test_with_seeded_rng_distinguishes_seeds() {
+        let pareto = Pareto::new(1.0, 1.5).unwrap();
+
+        let workers_a = pareto
+            .iter()
+            .with_seeded_rng(Pcg64::seed_from_u64(42), 42)
+            .split(4);
+        let workers_b = pareto
+            .iter()
+            .with_seeded_rng(Pcg64::seed_from_u64(999), 999)
+            .split(4);
+
+        let seq_a: Vec<f64> = workers_a.into_iter().flat_map(|w| w.take(5)).collect();
+        let seq_b: Vec<f64> = workers_b.into_iter().flat_map(|w| w.take(5)).collect();
+        assert_ne!(
+            seq_a, seq_b,
+            "different seeds plugged in via with_seeded_rng must not collapse to the same streams"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "split/stream require a known master seed")]
+    fn test_with_rng_split_panics_without_a_seed() {
+        let pareto = Pareto::new(1.0, 1.5).unwrap();
+        pareto.iter().with_rng(Pcg64::seed_from_u64(42)).split(4);
+    }
+
+    #[test]
+    fn test_skip_ahead_zero_is_a_no_op() {
+        let pareto = Pareto::new(1.0, 1.5).unwrap();
+
+        let mut untouched = pareto.iter().with_rng(Pcg64::seed_from_u64(1));
+        let mut skipped = pareto.iter().with_rng(Pcg64::seed_from_u64(1));
+        skipped.skip_ahead(0);
+
+        assert_eq!(untouched.next(), skipped.next());
+    }
+}
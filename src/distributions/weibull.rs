@@ -0,0 +1,115 @@
+use rand::rngs::StdRng;
+
+use crate::distributions::Distribution;
+use crate::distributions::DistributionError;
+use crate::distributions::SampleIterator;
+
+/// Weibull distribution, sampled via its closed-form inverse CDF.
+///
+/// The Weibull struct caches the inverse shape exponent at construction for
+/// efficient generation of Weibull-distributed values.
+#[derive(Debug, Clone, Copy)]
+pub struct Weibull {
+    /// Scale parameter, `lambda > 0`.
+    lambda: f64,
+    /// Shape parameter, `k > 0`.
+    #[allow(dead_code)]
+    k: f64,
+    /// Cached `1 / k`.
+    inv_k: f64,
+}
+
+impl Weibull {
+    /// Creates a Weibull distribution with scale `lambda > 0` and shape `k > 0`.
+    ///
+    /// # Examples
+    /// ```
+    /// use load::distributions::Weibull;
+    /// let weibull = Weibull::new(1.0, 2.0).unwrap();
+    /// let value = weibull.sample(0.5);
+    /// assert_eq!(format!("{:.4}", value), "0.8326");
+    /// ```
+    pub fn new(lambda: f64, k: f64) -> Result<Self, DistributionError> {
+        if lambda <= 0.0 {
+            return Err(DistributionError::InvalidScale(lambda));
+        }
+        if k <= 0.0 {
+            return Err(DistributionError::InvalidShape(k));
+        }
+
+        Ok(Self {
+            lambda,
+            k,
+            inv_k: 1.0 / k,
+        })
+    }
+
+    /// Converts a uniform random value `u ∈ [0, 1)` to a Weibull-distributed variate.
+    #[inline]
+    pub fn sample(&self, u: f64) -> f64 {
+        self.lambda * (-(1.0 - u).ln()).powf(self.inv_k)
+    }
+
+    /// Batch sample multiple values for better performance.
+    pub fn sample_batch(&self, u_values: &[f64], output: &mut [f64]) {
+        assert_eq!(
+            u_values.len(),
+            output.len(),
+            "Input and output slices must have the same length"
+        );
+
+        for (u, out) in u_values.iter().zip(output.iter_mut()) {
+            *out = self.sample(*u);
+        }
+    }
+
+    /// Creates an infinite iterator that yields Weibull-distributed values with a default random number generator.
+    pub fn iter(&self) -> WeibullIterator {
+        SampleIterator::new(*self)
+    }
+}
+
+impl Distribution for Weibull {
+    #[inline]
+    fn sample(&self, u: f64) -> f64 {
+        Weibull::sample(self, u)
+    }
+}
+
+/// Iterator that generates Weibull-distributed values using the default,
+/// cryptographically secure `StdRng`.
+///
+/// A type alias over the shared, distribution-generic [`SampleIterator`].
+pub type WeibullIterator = SampleIterator<Weibull, StdRng>;
+
+#[cfg(test)]
+mod tests {
+    use crate::distributions::*;
+
+    #[test]
+    fn test_weibull_rejects_invalid_params() {
+        assert!(Weibull::new(0.0, 2.0).is_err());
+        assert!(Weibull::new(-1.0, 2.0).is_err());
+        assert!(Weibull::new(1.0, 0.0).is_err());
+        assert!(Weibull::new(1.0, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_weibull_values_non_negative() {
+        let weibull = Weibull::new(2.0, 1.5).unwrap();
+        let samples: Vec<f64> = weibull.iter().take(1000).collect();
+        assert!(samples.iter().all(|&v| v >= 0.0));
+    }
+
+    #[test]
+    fn test_weibull_sample_batch_matches_sample() {
+        let weibull = Weibull::new(1.0, 1.2).unwrap();
+        let u_values = vec![0.1, 0.25, 0.5, 0.75, 0.9];
+        let mut output = vec![0.0; u_values.len()];
+        weibull.sample_batch(&u_values, &mut output);
+
+        for (u, expected) in u_values.iter().zip(output.iter()) {
+            assert_eq!(weibull.sample(*u), *expected);
+        }
+    }
+}
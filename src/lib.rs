@@ -0,0 +1,4 @@
+//! Seeded, reproducible distribution samplers for load testing.
+
+pub mod distributions;
+pub mod zipf;
@@ -0,0 +1,258 @@
+use rand::prelude::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+use crate::zipf::ZipfError;
+
+const DEFAULT_SEED: u64 = 666;
+
+/// O(1)-per-draw Zipf sampler built with Vose's alias method.
+///
+/// [`Zipf::sample`](crate::zipf::Zipf::sample) and
+/// [`ZipfDiscrete::sample`](crate::zipf::ZipfDiscrete::sample) both spend a
+/// `powf`/`exp` call per draw. `ZipfAlias` trades that for an O(n) one-time
+/// build over the discrete weights `w_k = k^-s` for `k in 1..=n`, after which
+/// every draw is one table lookup plus one comparison.
+#[derive(Debug, Clone)]
+pub struct ZipfAlias {
+    n: usize,
+    /// `prob[i]` is the probability of keeping the `i`-th bucket outright.
+    prob: Vec<f64>,
+    /// `alias[i]` is the index to fall back to when the `i`-th bucket is rejected.
+    alias: Vec<usize>,
+}
+
+impl ZipfAlias {
+    /// Builds an alias table over the discrete Zipf weights `k^-s` for `k in 1..=n`.
+    pub fn new(n: usize, s: f64) -> Result<Self, ZipfError> {
+        if s <= 0.0 {
+            return Err(ZipfError::InvalidPowerParameter(s));
+        }
+        if n == 0 {
+            return Err(ZipfError::EmptyArray);
+        }
+
+        let weights: Vec<f64> = (1..=n).map(|k| (k as f64).powf(-s)).collect();
+        Self::from_weights(&weights)
+    }
+
+    /// Builds an alias table over an arbitrary (unnormalized) weight slice.
+    ///
+    /// `weights[i]` is the relative weight of outcome `i`; [`sample`](Self::sample)
+    /// then returns `i + 1`, a 1-based rank into `weights`.
+    ///
+    /// # Examples
+    /// ```
+    /// use load::zipf::ZipfAlias;
+    ///
+    /// let alias = ZipfAlias::from_weights(&[4.0, 1.0, 1.0]).unwrap();
+    /// let rank = alias.sample(0.1, 0.5);
+    /// assert!((1..=3).contains(&rank));
+    /// ```
+    pub fn from_weights(weights: &[f64]) -> Result<Self, ZipfError> {
+        let n = weights.len();
+        if n == 0 {
+            return Err(ZipfError::EmptyArray);
+        }
+
+        let sum: f64 = weights.iter().sum();
+        // Normalize so the average weight is 1, as Vose's algorithm expects.
+        let mut scaled: Vec<f64> = weights.iter().map(|w| w / sum * n as f64).collect();
+
+        let mut small: Vec<usize> = Vec::new();
+        let mut large: Vec<usize> = Vec::new();
+        for (i, &p) in scaled.iter().enumerate() {
+            if p < 1.0 {
+                small.push(i);
+            } else {
+                large.push(i);
+            }
+        }
+
+        let mut prob = vec![0.0; n];
+        let mut alias = vec![0usize; n];
+
+        while !small.is_empty() && !large.is_empty() {
+            let s_idx = small.pop().expect("small is non-empty");
+            let l_idx = large.pop().expect("large is non-empty");
+
+            prob[s_idx] = scaled[s_idx];
+            alias[s_idx] = l_idx;
+
+            scaled[l_idx] -= 1.0 - scaled[s_idx];
+            if scaled[l_idx] < 1.0 {
+                small.push(l_idx);
+            } else {
+                large.push(l_idx);
+            }
+        }
+
+        // Leftover entries only fall outside [prob=1] due to floating-point
+        // rounding; treat them as certain to keep their own bucket.
+        for idx in large.into_iter().chain(small) {
+            prob[idx] = 1.0;
+        }
+
+        Ok(Self { n, prob, alias })
+    }
+
+    /// Draws one sample, returning a 1-based rank in `1..=n`, matching
+    /// [`ZipfDiscrete::sample`](crate::zipf::ZipfDiscrete::sample)'s convention.
+    ///
+    /// `u1` picks the bucket and `u2` decides whether to keep it or fall back
+    /// to its alias; both must be independent uniform values in `[0, 1)`.
+    #[inline]
+    pub fn sample(&self, u1: f64, u2: f64) -> usize {
+        let i = ((u1 * self.n as f64) as usize).min(self.n - 1);
+        if u2 < self.prob[i] {
+            i + 1
+        } else {
+            self.alias[i] + 1
+        }
+    }
+
+    /// Batch sample multiple values for better performance.
+    pub fn sample_batch(&self, u1_values: &[f64], u2_values: &[f64], output: &mut [usize]) {
+        assert_eq!(
+            u1_values.len(),
+            u2_values.len(),
+            "u1 and u2 slices must have the same length"
+        );
+        assert_eq!(
+            u1_values.len(),
+            output.len(),
+            "Input and output slices must have the same length"
+        );
+
+        for ((u1, u2), out) in u1_values
+            .iter()
+            .zip(u2_values.iter())
+            .zip(output.iter_mut())
+        {
+            *out = self.sample(*u1, *u2);
+        }
+    }
+
+    /// Creates an infinite iterator that yields sampled indices with a default random number generator.
+    pub fn iter(&self) -> ZipfAliasIterator {
+        GenericZipfAliasIterator::new(self.clone())
+    }
+}
+
+/// Iterator that draws from a [`ZipfAlias`] table using the default, cryptographically
+/// secure `StdRng`.
+///
+/// This is a type alias over [`GenericZipfAliasIterator`], matching the
+/// `ZipfIterator`/`GenericZipfIterator` split used for [`Zipf`](crate::zipf::Zipf).
+pub type ZipfAliasIterator = GenericZipfAliasIterator<StdRng>;
+
+/// Iterator that draws indices from a [`ZipfAlias`] table with a configurable
+/// random number generator `R`.
+#[derive(Debug, Clone)]
+pub struct GenericZipfAliasIterator<R: Rng> {
+    alias: ZipfAlias,
+    rng: R,
+}
+
+impl GenericZipfAliasIterator<StdRng> {
+    /// Creates a new `GenericZipfAliasIterator` with a default-seeded `StdRng`.
+    pub fn new(alias: ZipfAlias) -> Self {
+        Self {
+            alias,
+            rng: StdRng::seed_from_u64(DEFAULT_SEED),
+        }
+    }
+
+    /// Creates a new `GenericZipfAliasIterator` with the specified seed.
+    pub fn with_seed(alias: ZipfAlias, seed: u64) -> Self {
+        Self::new(alias).with_rng(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl<R: Rng> GenericZipfAliasIterator<R> {
+    /// Creates a new `GenericZipfAliasIterator` with the provided random number generator.
+    pub fn with_rng<R2: Rng>(self, rng: R2) -> GenericZipfAliasIterator<R2> {
+        GenericZipfAliasIterator {
+            alias: self.alias,
+            rng,
+        }
+    }
+}
+
+impl<R: Rng> Iterator for GenericZipfAliasIterator<R> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let u1 = self.rng.r#gen::<f64>();
+        let u2 = self.rng.r#gen::<f64>();
+        Some(self.alias.sample(u1, u2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::zipf::*;
+
+    #[test]
+    fn test_alias_rejects_invalid_params() {
+        assert!(ZipfAlias::new(10, 0.0).is_err());
+        assert!(ZipfAlias::new(0, 1.1).is_err());
+        assert!(ZipfAlias::from_weights(&[]).is_err());
+    }
+
+    #[test]
+    fn test_alias_all_indices_in_range() {
+        let alias = ZipfAlias::new(9, 0.8).unwrap();
+        let samples: Vec<usize> = alias.iter().take(1000).collect();
+        assert!(samples.iter().all(|&rank| (1..=9).contains(&rank)));
+    }
+
+    #[test]
+    fn test_alias_count_distribution() {
+        let alias = Zipf::alias(9, 0.8).unwrap();
+        let counts = alias.iter().take(1000).fold(HashMap::new(), |mut acc, x| {
+            *acc.entry(x).or_insert(0) += 1;
+            acc
+        });
+
+        // got: {1: 304, 2: 162, 3: 115, 4: 88, 5: 80, 6: 81, 7: 51, 8: 63, 9: 56}
+        assert_eq!(
+            counts,
+            HashMap::from_iter([
+                (1, 304),
+                (2, 162),
+                (3, 115),
+                (4, 88),
+                (5, 80),
+                (6, 81),
+                (7, 51),
+                (8, 63),
+                (9, 56),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_alias_matches_uniform_weights() {
+        let alias = ZipfAlias::from_weights(&[1.0, 1.0, 1.0, 1.0]).unwrap();
+        let counts = alias.iter().take(4000).fold(HashMap::new(), |mut acc, x| {
+            *acc.entry(x).or_insert(0) += 1;
+            acc
+        });
+        assert!(
+            counts.keys().all(|&rank| (1..=4).contains(&rank)),
+            "ranks should be 1-based: {:?}",
+            counts
+        );
+        for count in counts.values() {
+            assert!(
+                (900..1100).contains(count),
+                "counts should be roughly even: {:?}",
+                counts
+            );
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+use crate::zipf::ZipfError;
+
+const DEFAULT_SEED: u64 = 666;
+
+/// `g(t) = (e^t - 1) / t`, continuously extended with `g(0) = 1`.
+///
+/// This is the numerically stable building block for [`big_h`] and
+/// [`big_h_inv`]: evaluating `(e^t - 1) / t` directly loses precision as `t`
+/// approaches 0, so we special-case the region where cancellation would bite.
+#[inline]
+fn g(t: f64) -> f64 {
+    if t.abs() < 1e-12 {
+        1.0
+    } else {
+        t.exp_m1() / t
+    }
+}
+
+/// Inverse of `y = t * g(t)`, i.e. `g_inv(y) = ln(1+y) / y` with `g_inv(0) = 1`.
+#[inline]
+fn g_inv(y: f64) -> f64 {
+    if y.abs() < 1e-12 {
+        1.0
+    } else {
+        y.ln_1p() / y
+    }
+}
+
+/// The antiderivative of `h(x) = x^-s`, i.e. `H(x) = (x^(1-s) - 1) / (1-s)`
+/// for `s != 1` and `H(x) = ln(x)` for `s == 1`.
+///
+/// Computed through `g` so the `s == 1` case falls out of the same formula
+/// instead of needing a branch.
+#[inline]
+fn big_h(x: f64, s: f64) -> f64 {
+    let log_x = x.ln();
+    log_x * g((1.0 - s) * log_x)
+}
+
+/// Inverse of [`big_h`].
+#[inline]
+fn big_h_inv(u: f64, s: f64) -> f64 {
+    let t = 1.0 - s;
+    (u * g_inv(t * u)).exp()
+}
+
+/// The (unnormalized) Zipf density `h(x) = x^-s`.
+#[inline]
+fn h(x: f64, s: f64) -> f64 {
+    x.powf(-s)
+}
+
+/// Exact discrete Zipf distribution over `{1..=n}`, sampled via the
+/// Hörmann–Derflinger rejection-inversion method.
+///
+/// Unlike [`Zipf`](crate::zipf::Zipf), which maps a uniform value through a
+/// continuous inverse-CDF and truncates to an integer, `ZipfDiscrete` samples
+/// exact integers whose probabilities are proportional to `k^-s` for
+/// `k in 1..=n`. It stays `O(1)` per draw (amortized, via rejection) without
+/// precomputing a table, at the cost of needing its own random draws per
+/// sample rather than accepting one common random value.
+#[derive(Debug, Clone, Copy)]
+pub struct ZipfDiscrete {
+    n: usize,
+    s: f64,
+    /// cached `H(1.5) - h(1)`, the lower bound of the sampling domain, adjusted
+    /// so the half-width bucket at `k=1` gets its correct share of mass.
+    hx1: f64,
+    /// cached `H(n + 0.5)`, the upper bound of the sampling domain.
+    hxn: f64,
+    /// cached squeeze constant used to short-circuit the rejection test.
+    s_const: f64,
+}
+
+impl ZipfDiscrete {
+    /// Creates a `ZipfDiscrete` over `{1..=n}` with power parameter `s > 0`.
+    ///
+    /// # Arguments
+    /// * `n` - Number of distinct ranks, must be >= 1
+    /// * `s` - Power parameter, must be > 0
+    ///
+    /// # Examples
+    /// ```
+    /// use load::zipf::ZipfDiscrete;
+    /// use rand::SeedableRng;
+    ///
+    /// let zipf = ZipfDiscrete::new(10, 1.1).unwrap();
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+    /// let k = zipf.sample(&mut rng);
+    /// assert!((1..=10).contains(&k));
+    /// ```
+    pub fn new(n: usize, s: f64) -> Result<Self, ZipfError> {
+        if s <= 0.0 {
+            return Err(ZipfError::InvalidPowerParameter(s));
+        }
+        if n == 0 {
+            return Err(ZipfError::EmptyArray);
+        }
+
+        let hx1 = big_h(1.5, s) - h(1.0, s);
+        let hxn = big_h(n as f64 + 0.5, s);
+        let s_const = 2.0 - big_h_inv(big_h(2.5, s) - h(2.0, s), s);
+
+        Ok(Self {
+            n,
+            s,
+            hx1,
+            hxn,
+            s_const,
+        })
+    }
+
+    /// Draws one exact Zipf-distributed integer in `{1..=n}` using `rng`.
+    ///
+    /// On average this takes `O(1)` draws from `rng`: the rejection step
+    /// almost always accepts on the first try.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let lo = self.hx1.min(self.hxn);
+        let hi = self.hx1.max(self.hxn);
+
+        loop {
+            let u = rng.gen_range(lo..hi);
+            let x = big_h_inv(u, self.s);
+            let k = ((x + 0.5).floor() as i64).clamp(1, self.n as i64) as usize;
+            let k_f64 = k as f64;
+
+            if k_f64 - x <= self.s_const || u >= big_h(k_f64 + 0.5, self.s) - h(k_f64, self.s) {
+                return k;
+            }
+        }
+    }
+
+    /// Creates an infinite iterator that yields exact Zipf-distributed ranks
+    /// with a default random number generator.
+    pub fn iter(&self) -> ZipfDiscreteIterator {
+        ZipfDiscreteIterator::new(*self)
+    }
+}
+
+/// Iterator that generates exact discrete Zipf-distributed ranks with a
+/// configurable random seed.
+///
+/// # Examples
+/// ```
+/// use load::zipf::ZipfDiscrete;
+///
+/// let zipf = ZipfDiscrete::new(9, 0.8).unwrap();
+/// let samples: Vec<usize> = zipf.iter().take(5).collect();
+/// assert!(samples.iter().all(|&k| (1..=9).contains(&k)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ZipfDiscreteIterator {
+    zipf: ZipfDiscrete,
+    rng: StdRng,
+}
+
+impl ZipfDiscreteIterator {
+    /// Creates a new `ZipfDiscreteIterator` with default seed.
+    pub fn new(zipf: ZipfDiscrete) -> Self {
+        Self {
+            zipf,
+            rng: StdRng::seed_from_u64(DEFAULT_SEED),
+        }
+    }
+
+    /// Creates a new `ZipfDiscreteIterator` with the provided random number generator.
+    pub fn with_rng(self, rng: StdRng) -> Self {
+        Self {
+            zipf: self.zipf,
+            rng,
+        }
+    }
+
+    /// Creates a new `ZipfDiscreteIterator` with the specified seed.
+    pub fn with_seed(zipf: ZipfDiscrete, seed: u64) -> Self {
+        Self::new(zipf).with_rng(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl Iterator for ZipfDiscreteIterator {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.zipf.sample(&mut self.rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::zipf::*;
+
+    #[test]
+    fn test_discrete_rejects_invalid_params() {
+        assert!(ZipfDiscrete::new(10, 0.0).is_err());
+        assert!(ZipfDiscrete::new(10, -1.0).is_err());
+        assert!(ZipfDiscrete::new(0, 1.1).is_err());
+    }
+
+    #[test]
+    fn test_discrete_all_ranks_in_range() {
+        let zipf = ZipfDiscrete::new(9, 0.8).unwrap();
+        let samples: Vec<usize> = zipf.iter().take(1000).collect();
+        assert!(samples.iter().all(|&k| (1..=9).contains(&k)));
+    }
+
+    #[test]
+    fn test_discrete_count_distribution() {
+        let zipf = ZipfDiscrete::new(9, 0.8).unwrap();
+        let counts = zipf.iter().take(1000).fold(HashMap::new(), |mut acc, x| {
+            *acc.entry(x).or_insert(0) += 1;
+            acc
+        });
+
+        // got: {1: 278, 2: 162, 3: 119, 4: 102, 5: 79, 6: 70, 7: 75, 8: 61, 9: 54}
+        assert_eq!(
+            counts,
+            HashMap::from_iter([
+                (1, 278),
+                (2, 162),
+                (3, 119),
+                (4, 102),
+                (5, 79),
+                (6, 70),
+                (7, 75),
+                (8, 61),
+                (9, 54),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_discrete_monotonic_weight() {
+        // Rank 1 must always be the most frequent for a skewed Zipf distribution.
+        let zipf = ZipfDiscrete::new(9, 2.0).unwrap();
+        let counts = zipf.iter().take(10000).fold(HashMap::new(), |mut acc, x| {
+            *acc.entry(x).or_insert(0) += 1;
+            acc
+        });
+        assert!(counts[&1] > counts[&2] * 2);
+    }
+}
@@ -1,12 +1,17 @@
-use rand::prelude::StdRng;
-use rand::Rng;
-use rand::SeedableRng;
+use rand::rngs::StdRng;
 
+use crate::distributions::SampleIterator;
 use crate::zipf::Zipf;
 
-const DEFAULT_SEED: u64 = 666;
-
-/// Iterator that generates zipf-distributed values with configurable random seed.
+/// Iterator that generates zipf-distributed values using the default,
+/// cryptographically secure `StdRng`.
+///
+/// This is a type alias over the distribution-generic
+/// [`SampleIterator`](crate::distributions::SampleIterator), so existing code
+/// that names `ZipfIterator` keeps compiling unchanged. For throughput-bound
+/// load tests where ChaCha's CSPRNG overhead matters, plug in a faster
+/// non-cryptographic generator (e.g. `rand_pcg::Pcg64` or
+/// `rand::rngs::SmallRng`) via [`GenericZipfIterator::with_rng`] instead.
 ///
 /// # Examples
 /// ```
@@ -24,57 +29,72 @@ const DEFAULT_SEED: u64 = 666;
 /// assert_eq!(formatted, vec!["1.4376", "3.7734", "2.5003"]);
 ///
 /// // Create iterator with custom seed (convenient)
-/// let mut iter = ZipfIterator::with_seed(zipf.clone(), 42);
+/// let mut iter = ZipfIterator::with_seed(zipf, 42);
 /// let values1: Vec<f64> = (&mut iter).take(3).collect();
 /// let formatted1: Vec<String> = values1.iter().map(|v| format!("{:.4}", v)).collect();
 /// assert_eq!(formatted1, vec!["3.6130", "3.8215", "5.4799"]);
 ///
-/// // Create iterator with custom RNG (fluent API)
+/// // Create iterator with custom RNG (fluent API). `split`/`stream` panic on
+/// // the result, since the iterator has no way to know what
+/// // seed `rng` started from; use `with_seeded_rng` if you need both.
 /// let rng = StdRng::seed_from_u64(123);
 /// let mut iter = zipf.iter().with_rng(rng);
 /// let values2: Vec<f64> = (&mut iter).take(3).collect();
 /// let formatted2: Vec<String> = values2.iter().map(|v| format!("{:.4}", v)).collect();
 /// assert_eq!(formatted2, vec!["1.4036", "1.3429", "37.9130"]);
 /// ```
-#[derive(Debug, Clone)]
-pub struct ZipfIterator {
-    zipf: Zipf,
-    rng: StdRng,
-}
-
-impl ZipfIterator {
-    /// Creates a new ZipfIterator with default seed.
-    pub fn new(zipf: Zipf) -> Self {
-        Self {
-            zipf,
-            rng: StdRng::seed_from_u64(DEFAULT_SEED),
-        }
-    }
-
-    /// Creates a new ZipfIterator with the provided random number generator.
-    pub fn with_rng(self, rng: StdRng) -> Self {
-        Self {
-            zipf: self.zipf,
-            rng,
-        }
-    }
-
-    /// Creates a new ZipfIterator with the specified seed.
-    /// This is a convenience method that creates an StdRng internally.
-    pub fn with_seed(zipf: Zipf, seed: u64) -> Self {
-        Self::new(zipf).with_rng(StdRng::seed_from_u64(seed))
-    }
-}
-
-impl Iterator for ZipfIterator {
-    type Item = f64;
+///
+/// Plugging in a non-cryptographic RNG for raw throughput:
+/// ```
+/// use load::zipf::Zipf;
+/// use rand_pcg::Pcg64;
+/// use rand::SeedableRng;
+///
+/// let zipf = Zipf::new(1.0..100.0, 1.5).unwrap();
+/// let mut iter = zipf.iter().with_rng(Pcg64::seed_from_u64(42));
+/// let value = iter.next().unwrap();
+/// assert!((1.0..=100.0).contains(&value));
+/// ```
+///
+/// Combining a non-cryptographic RNG with reproducible per-worker streams
+/// requires `with_seeded_rng`, so the master seed feeding `split`/`stream`
+/// stays in sync with the RNG actually driving the iterator:
+/// ```
+/// use load::zipf::Zipf;
+/// use rand_pcg::Pcg64;
+/// use rand::SeedableRng;
+///
+/// let zipf = Zipf::new(1.0..100.0, 1.5).unwrap();
+/// let mut workers = zipf
+///     .iter()
+///     .with_seeded_rng(Pcg64::seed_from_u64(42), 42)
+///     .split(4);
+/// assert_eq!(workers.len(), 4);
+/// ```
+///
+/// Deriving independent, reproducible per-worker streams from one master seed:
+/// ```
+/// use load::zipf::Zipf;
+/// use load::zipf::ZipfIterator;
+///
+/// let zipf = Zipf::new(1.0..100.0, 1.5).unwrap();
+/// let mut workers = ZipfIterator::with_seed(zipf, 42).split(64);
+/// assert_eq!(workers.len(), 64);
+/// // Reproducing worker 7's sequence only requires the master seed.
+/// let replay = ZipfIterator::with_seed(zipf, 42).stream(7, 64);
+/// assert_eq!(
+///     workers[7].by_ref().take(5).collect::<Vec<_>>(),
+///     replay.take(5).collect::<Vec<_>>()
+/// );
+/// ```
+pub type ZipfIterator = SampleIterator<Zipf, StdRng>;
 
-    #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        let u = self.rng.r#gen::<f64>();
-        Some(self.zipf.sample(u))
-    }
-}
+/// Iterator that generates zipf-distributed values with a configurable
+/// random number generator `R`.
+///
+/// A type alias over the distribution-generic
+/// [`SampleIterator`](crate::distributions::SampleIterator).
+pub type GenericZipfIterator<R> = SampleIterator<Zipf, R>;
 
 #[cfg(test)]
 mod tests {
@@ -89,8 +109,8 @@ mod tests {
         let zipf = Zipf::new(1.0..10.0, 1.5).unwrap();
 
         // Test that same seed produces same sequence
-        let iter1 = ZipfIterator::with_seed(zipf.clone(), 42);
-        let iter2 = ZipfIterator::with_seed(zipf.clone(), 42);
+        let iter1 = ZipfIterator::with_seed(zipf, 42);
+        let iter2 = ZipfIterator::with_seed(zipf, 42);
 
         let seq1: Vec<f64> = iter1.take(10).collect();
         let seq2: Vec<f64> = iter2.take(10).collect();
@@ -101,7 +121,7 @@ mod tests {
     #[test]
     fn test_zipf_iterator_different_rngs() {
         let zipf = Zipf::new(1.0..5.0, 1.2).unwrap();
-        let mut iter = ZipfIterator::with_seed(zipf.clone(), 123);
+        let mut iter = ZipfIterator::with_seed(zipf, 123);
 
         let seq1: Vec<f64> = (&mut iter).take(5).collect();
 
@@ -129,4 +149,49 @@ mod tests {
 
         assert_eq!(seq1, seq2, "Same seed should reproduce identical sequence");
     }
+
+    #[test]
+    fn test_zipf_iterator_split_is_reproducible() {
+        let zipf = Zipf::new(1.0..10.0, 1.5).unwrap();
+
+        let workers1 = ZipfIterator::with_seed(zipf, 42).split(8);
+        let workers2 = ZipfIterator::with_seed(zipf, 42).split(8);
+        assert_eq!(workers1.len(), 8);
+
+        for (mut w1, mut w2) in workers1.into_iter().zip(workers2) {
+            let seq1: Vec<f64> = (&mut w1).take(10).collect();
+            let seq2: Vec<f64> = (&mut w2).take(10).collect();
+            assert_eq!(seq1, seq2, "Same master seed should reproduce each worker");
+        }
+    }
+
+    #[test]
+    fn test_zipf_iterator_split_streams_are_independent() {
+        let zipf = Zipf::new(1.0..10.0, 1.5).unwrap();
+        let mut workers = ZipfIterator::with_seed(zipf, 42).split(4);
+
+        let sequences: Vec<Vec<f64>> = workers.iter_mut().map(|w| w.take(20).collect()).collect();
+
+        for i in 0..sequences.len() {
+            for j in (i + 1)..sequences.len() {
+                assert_ne!(
+                    sequences[i], sequences[j],
+                    "Different workers should not draw identical sequences"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_zipf_iterator_stream_matches_split() {
+        let zipf = Zipf::new(1.0..10.0, 1.5).unwrap();
+        let master = ZipfIterator::with_seed(zipf, 7);
+
+        let mut from_split = master.split(16).remove(5);
+        let mut from_stream = master.stream(5, 16);
+
+        let seq1: Vec<f64> = (&mut from_split).take(10).collect();
+        let seq2: Vec<f64> = (&mut from_stream).take(10).collect();
+        assert_eq!(seq1, seq2, "stream(id, count) must match split(count)[id]");
+    }
 }
@@ -4,11 +4,19 @@
 //! See: <https://en.wikipedia.org/wiki/Zipf%27s_law>
 #![doc = include_str!("README.md")]
 
+mod alias;
+mod discrete;
 mod errors;
 mod iterator;
 #[allow(clippy::module_inception)]
 mod zipf;
 
+pub use alias::GenericZipfAliasIterator;
+pub use alias::ZipfAlias;
+pub use alias::ZipfAliasIterator;
+pub use discrete::ZipfDiscrete;
+pub use discrete::ZipfDiscreteIterator;
 pub use errors::ZipfError;
+pub use iterator::GenericZipfIterator;
 pub use iterator::ZipfIterator;
 pub use zipf::Zipf;
@@ -1,5 +1,8 @@
 use std::ops::Range;
 
+use crate::distributions::Distribution;
+use crate::zipf::ZipfAlias;
+use crate::zipf::ZipfDiscrete;
 use crate::zipf::ZipfError;
 use crate::zipf::ZipfIterator;
 
@@ -201,6 +204,35 @@ impl Zipf {
         ZipfIterator::new(*self)
     }
 
+    /// Creates an exact discrete Zipf distribution over `{1..=n}`.
+    ///
+    /// Unlike [`Zipf::sample`], which maps a uniform value through a
+    /// continuous inverse-CDF and truncates to an integer, this samples
+    /// exact integers whose probabilities are proportional to `k^-s`, via
+    /// the Hörmann–Derflinger rejection-inversion method.
+    ///
+    /// # Arguments
+    /// * `n` - Number of distinct ranks, must be >= 1
+    /// * `s` - Power parameter, must be > 0
+    pub fn new_discrete(n: usize, s: f64) -> Result<ZipfDiscrete, ZipfError> {
+        ZipfDiscrete::new(n, s)
+    }
+
+    /// Builds an O(1)-per-draw alias-method sampler over the discrete Zipf
+    /// weights `k^-s` for `k in 1..=n`.
+    ///
+    /// Precomputes Vose's alias table, trading an O(n) one-time build for
+    /// replacing the per-sample `powf`/`exp` call with a table lookup and a
+    /// comparison. Sampled values are 1-based ranks in `1..=n`, matching
+    /// [`Zipf::new_discrete`]'s convention.
+    ///
+    /// # Arguments
+    /// * `n` - Number of distinct ranks, must be >= 1
+    /// * `s` - Power parameter, must be > 0
+    pub fn alias(n: usize, s: f64) -> Result<ZipfAlias, ZipfError> {
+        ZipfAlias::new(n, s)
+    }
+
     /// Returns an iterator that yields shuffled array indices following zipf distribution.
     ///
     /// # Arguments
@@ -245,6 +277,13 @@ impl Zipf {
     }
 }
 
+impl Distribution for Zipf {
+    #[inline]
+    fn sample(&self, u: f64) -> f64 {
+        Zipf::sample(self, u)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;